@@ -1,55 +1,239 @@
-use std::{fmt::Display, ops::Bound};
-use chrono::{DateTime, Utc};
+use std::{cmp::Ordering, fmt::Display, ops::Bound};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+#[cfg(feature = "with-rust_decimal")]
+use rust_decimal::Decimal;
 use sea_orm::{prelude::*, sea_query::{Nullable, SimpleExpr, ValueType, ValueTypeErr}, ColIdx, TryGetable, Value};
 use serde::{Deserialize, Serialize};
 use sqlx::postgres::types::PgRange;
-use anyhow::anyhow;
+#[cfg(feature = "with-time")]
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+/// The element type of a Postgres range.
+///
+/// `chrono` is the base datetime backend: `timestamptz` maps to
+/// [`chrono::DateTime<Utc>`] out of the box. Enabling the `with-time` feature
+/// adds the same surface for [`time::OffsetDateTime`], so projects standardized
+/// on the `time` crate get an identical `ValueType`/`TryGetable`/`Display`/parse
+/// implementation through this one code path rather than a fork.
+///
+/// Everything that differs between `tstzrange`, `int4range`, `numrange`, …
+/// lives behind this trait: how a single bound is parsed out of the text
+/// representation, how it is rendered back, and the Postgres type name the
+/// surrounding range reports to SeaORM. [`PgRangeType`] is generic over it so
+/// the `from_string`/`Display`/`ValueType`/`TryGetable` machinery is written
+/// exactly once.
+pub trait RangeBound: Clone + PartialOrd + Sized {
+    /// SeaORM `type_name()` for the range, e.g. `"tstzrange"`.
+    const TYPE_NAME: &'static str;
+    /// Postgres column type as rendered by `column_type()`, e.g. `"TSTZRANGE"`.
+    const COLUMN_TYPE: &'static str;
+
+    /// Parse a single bound value out of its text representation.
+    fn parse_bound(s: &str) -> Result<Self, ValueTypeErr>;
+
+    /// Render a single bound value back into Postgres range-literal text.
+    fn format_bound(&self) -> String;
+}
 
-#[derive(Debug, Clone, PartialEq)]
-pub struct TstzRange(pub PgRange<DateTime<Utc>>);
+/// Strip a single layer of Postgres double-quoting from a bound literal.
+#[inline(always)]
+fn unquote(s: &str) -> &str {
+    let s = s.strip_prefix('"').unwrap_or(s);
+    s.strip_suffix('"').unwrap_or(s)
+}
 
-impl TstzRange {
-    pub fn new(
-        start: Bound<DateTime<Utc>>,
-        end: Bound<DateTime<Utc>>,
-    ) -> Self {
-        TstzRange(PgRange { start, end })
+/// Postgres renders a zero UTC offset as `+00`, but the RFC 3339 parsers in the
+/// datetime backends want `+00:00`. Pad a lone trailing `+00` so both the
+/// `chrono` and `time` bounds parse the same text.
+fn pad_utc_offset(s: &str) -> std::borrow::Cow<'_, str> {
+    if s.matches('+').count() == 1 && s.ends_with("+00") {
+        std::borrow::Cow::Owned(format!("{s}:00"))
+    } else {
+        std::borrow::Cow::Borrowed(s)
     }
+}
 
-    #[inline(always)]
-    fn clean_and_parse(date_str: &str) -> Result<DateTime<Utc>, ValueTypeErr> {
-        let s = date_str.strip_prefix("\"").unwrap_or(date_str);
-        let s = s.strip_suffix("\"").unwrap_or(s);
+impl RangeBound for DateTime<Utc> {
+    const TYPE_NAME: &'static str = "tstzrange";
+    const COLUMN_TYPE: &'static str = "TSTZRANGE";
 
-        let s = if s.matches('+').count() == 1 && s.ends_with("+00") {
-            format!("{}:00", s)
-        } else {
-            s.to_string()
-        };
-        s.parse::<DateTime<Utc>>()
-        .inspect_err(|e| eprintln!("failed to parse dt: {e}"))
-        .map_err(|_| ValueTypeErr)
+    fn parse_bound(s: &str) -> Result<Self, ValueTypeErr> {
+        pad_utc_offset(unquote(s))
+            .parse::<DateTime<Utc>>()
+            .inspect_err(|e| eprintln!("failed to parse dt: {e}"))
+            .map_err(|_| ValueTypeErr)
     }
 
-    fn _parse_bound<T>(ch: char, value: Option<T>) -> Result<Bound<T>, anyhow::Error> {
-        Ok(if let Some(value) = value {
-            match ch {
-                '(' | ')' => Bound::Excluded(value),
-                '[' | ']' => Bound::Included(value),
+    fn format_bound(&self) -> String {
+        self.to_rfc3339()
+    }
+}
 
-                _ => {
+impl RangeBound for i32 {
+    const TYPE_NAME: &'static str = "int4range";
+    const COLUMN_TYPE: &'static str = "INT4RANGE";
 
-                    return Err(anyhow!(
-                        "expected `(`, ')', '[', or `]` but found `{ch}` for range literal"
-                    )
-                    );
-                }
+    fn parse_bound(s: &str) -> Result<Self, ValueTypeErr> {
+        unquote(s).parse::<i32>().map_err(|_| ValueTypeErr)
+    }
+
+    fn format_bound(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl RangeBound for i64 {
+    const TYPE_NAME: &'static str = "int8range";
+    const COLUMN_TYPE: &'static str = "INT8RANGE";
+
+    fn parse_bound(s: &str) -> Result<Self, ValueTypeErr> {
+        unquote(s).parse::<i64>().map_err(|_| ValueTypeErr)
+    }
+
+    fn format_bound(&self) -> String {
+        self.to_string()
+    }
+}
+
+#[cfg(feature = "with-rust_decimal")]
+impl RangeBound for Decimal {
+    const TYPE_NAME: &'static str = "numrange";
+    const COLUMN_TYPE: &'static str = "NUMRANGE";
+
+    fn parse_bound(s: &str) -> Result<Self, ValueTypeErr> {
+        unquote(s).parse::<Decimal>().map_err(|_| ValueTypeErr)
+    }
+
+    fn format_bound(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl RangeBound for NaiveDate {
+    const TYPE_NAME: &'static str = "daterange";
+    const COLUMN_TYPE: &'static str = "DATERANGE";
+
+    fn parse_bound(s: &str) -> Result<Self, ValueTypeErr> {
+        unquote(s).parse::<NaiveDate>().map_err(|_| ValueTypeErr)
+    }
+
+    fn format_bound(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl RangeBound for NaiveDateTime {
+    const TYPE_NAME: &'static str = "tsrange";
+    const COLUMN_TYPE: &'static str = "TSRANGE";
+
+    fn parse_bound(s: &str) -> Result<Self, ValueTypeErr> {
+        unquote(s).parse::<NaiveDateTime>().map_err(|_| ValueTypeErr)
+    }
+
+    fn format_bound(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Errors produced by the value-level range algebra on [`PgRangeType`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RangeError {
+    /// `union` was asked to combine two ranges that neither overlap nor are
+    /// adjacent, which would yield a discontinuous result.
+    Discontiguous,
+    /// `difference` would punch a hole in the middle of a range, leaving two
+    /// disjoint pieces rather than a single contiguous range.
+    DifferenceNotContiguous,
+}
+
+impl Display for RangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RangeError::Discontiguous => {
+                write!(f, "result of range union would not be contiguous")
             }
-        } else {
-            Bound::Unbounded
-        })
+            RangeError::DifferenceNotContiguous => {
+                write!(f, "result of range difference would not be contiguous")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RangeError {}
+
+#[cfg(feature = "with-time")]
+impl RangeBound for OffsetDateTime {
+    const TYPE_NAME: &'static str = "tstzrange";
+    const COLUMN_TYPE: &'static str = "TSTZRANGE";
+
+    fn parse_bound(s: &str) -> Result<Self, ValueTypeErr> {
+        OffsetDateTime::parse(&pad_utc_offset(unquote(s)), &Rfc3339).map_err(|_| ValueTypeErr)
+    }
+
+    fn format_bound(&self) -> String {
+        self.format(&Rfc3339).unwrap_or_default()
+    }
+}
+
+/// A Postgres range value wrapping [`PgRange<T>`] for any [`RangeBound`]
+/// element type. The concrete aliases below instantiate it for each builtin
+/// Postgres range type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PgRangeType<T>(pub PgRange<T>);
+
+/// `tstzrange` — a range of `timestamptz`, on the `chrono` backend.
+pub type TstzRange = PgRangeType<DateTime<Utc>>;
+/// `tstzrange` backed by the `time` crate's [`OffsetDateTime`].
+#[cfg(feature = "with-time")]
+pub type TstzRangeTime = PgRangeType<OffsetDateTime>;
+/// `int4range` — a range of `int4`.
+pub type Int4Range = PgRangeType<i32>;
+/// `int8range` — a range of `int8`.
+pub type Int8Range = PgRangeType<i64>;
+/// `numrange` — a range of `numeric`, backed by [`rust_decimal::Decimal`].
+#[cfg(feature = "with-rust_decimal")]
+pub type NumRange = PgRangeType<Decimal>;
+/// `daterange` — a range of `date`.
+pub type DateRange = PgRangeType<NaiveDate>;
+/// `tsrange` — a range of `timestamp`.
+pub type TsRange = PgRangeType<NaiveDateTime>;
+
+impl<T> PgRangeType<T> {
+    pub fn new(start: Bound<T>, end: Bound<T>) -> Self {
+        PgRangeType(PgRange { start, end })
+    }
+
+    pub fn start(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        match &self.0.start {
+            Bound::Included(v) | Bound::Excluded(v) => Some(v.clone()),
+            Bound::Unbounded => None,
+        }
+    }
+
+    pub fn end(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        match &self.0.end {
+            Bound::Included(v) | Bound::Excluded(v) => Some(v.clone()),
+            Bound::Unbounded => None,
+        }
+    }
+
+    // Check if start/end are inclusive
+    pub fn is_start_inclusive(&self) -> bool {
+        matches!(&self.0.start, Bound::Included(_))
     }
 
+    pub fn is_end_inclusive(&self) -> bool {
+        matches!(&self.0.end, Bound::Included(_))
+    }
+}
+
+impl<T: RangeBound> PgRangeType<T> {
     pub fn from_string(s: &str) -> Result<Self, ValueTypeErr> {
         let parts: Vec<&str> = s.split(',').collect();
         if parts.len() != 2 {
@@ -59,18 +243,16 @@ impl TstzRange {
         let start_str = parts[0];
         let end_str = parts[1];
 
-
         let start = if start_str == "(" {
             Bound::Unbounded
         } else {
             let inclusive = start_str.starts_with('[');
-            let date_str = &start_str[1..];
-            let dt = Self::clean_and_parse(date_str)?;
+            let value = T::parse_bound(&start_str[1..])?;
 
             if inclusive {
-                Bound::Included(dt)
+                Bound::Included(value)
             } else {
-                Bound::Excluded(dt)
+                Bound::Excluded(value)
             }
         };
 
@@ -78,110 +260,350 @@ impl TstzRange {
             Bound::Unbounded
         } else {
             let inclusive = end_str.ends_with(']');
-            let date_str = &end_str[0..end_str.len()-1];
-            let dt = Self::clean_and_parse(date_str)?;
+            let value = T::parse_bound(&end_str[0..end_str.len() - 1])?;
 
             if inclusive {
-                Bound::Included(dt)
+                Bound::Included(value)
             } else {
-                Bound::Excluded(dt)
+                Bound::Excluded(value)
             }
         };
 
-        Ok(TstzRange(PgRange { start, end }))
+        Ok(PgRangeType(PgRange { start, end }))
     }
 
+    /// Does this range contain `value`, honouring bound inclusivity?
+    pub fn contains_value(&self, value: &T) -> bool {
+        match (&self.0.start, &self.0.end) {
+            (Bound::Included(start), Bound::Included(end)) => value >= start && value <= end,
+            (Bound::Included(start), Bound::Excluded(end)) => value >= start && value < end,
+            (Bound::Excluded(start), Bound::Included(end)) => value > start && value <= end,
+            (Bound::Excluded(start), Bound::Excluded(end)) => value > start && value < end,
+            (Bound::Included(start), Bound::Unbounded) => value >= start,
+            (Bound::Excluded(start), Bound::Unbounded) => value > start,
+            (Bound::Unbounded, Bound::Included(end)) => value <= end,
+            (Bound::Unbounded, Bound::Excluded(end)) => value < end,
+            (Bound::Unbounded, Bound::Unbounded) => true,
+        }
+    }
+}
 
-    pub fn from_datetime_pair(start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
-        Self::new(Bound::Included(start), Bound::Excluded(end))
+impl<T: RangeBound> PgRangeType<T> {
+    /// Compare two *lower* bounds by position on the number line. `Unbounded`
+    /// is −∞; when the values tie, the exclusive bound is the greater one
+    /// because it starts fractionally later.
+    fn cmp_lower(a: &Bound<T>, b: &Bound<T>) -> Ordering {
+        match (a, b) {
+            (Bound::Unbounded, Bound::Unbounded) => Ordering::Equal,
+            (Bound::Unbounded, _) => Ordering::Less,
+            (_, Bound::Unbounded) => Ordering::Greater,
+            (Bound::Included(x) | Bound::Excluded(x), Bound::Included(y) | Bound::Excluded(y)) => {
+                match x.partial_cmp(y) {
+                    Some(Ordering::Equal) | None => {
+                        let ax = matches!(a, Bound::Excluded(_));
+                        let bx = matches!(b, Bound::Excluded(_));
+                        ax.cmp(&bx)
+                    }
+                    Some(o) => o,
+                }
+            }
+        }
+    }
+
+    /// Compare two *upper* bounds by position on the number line. `Unbounded`
+    /// is +∞; when the values tie, the exclusive bound is the lesser one
+    /// because it ends fractionally earlier.
+    fn cmp_upper(a: &Bound<T>, b: &Bound<T>) -> Ordering {
+        match (a, b) {
+            (Bound::Unbounded, Bound::Unbounded) => Ordering::Equal,
+            (Bound::Unbounded, _) => Ordering::Greater,
+            (_, Bound::Unbounded) => Ordering::Less,
+            (Bound::Included(x) | Bound::Excluded(x), Bound::Included(y) | Bound::Excluded(y)) => {
+                match x.partial_cmp(y) {
+                    Some(Ordering::Equal) | None => {
+                        let ax = matches!(a, Bound::Excluded(_));
+                        let bx = matches!(b, Bound::Excluded(_));
+                        bx.cmp(&ax)
+                    }
+                    Some(o) => o,
+                }
+            }
+        }
     }
 
+    /// Flip a bound's inclusivity, keeping its value — used to build the
+    /// complementary endpoint when differencing ranges.
+    fn flip(b: &Bound<T>) -> Bound<T> {
+        match b {
+            Bound::Included(v) => Bound::Excluded(v.clone()),
+            Bound::Excluded(v) => Bound::Included(v.clone()),
+            Bound::Unbounded => Bound::Unbounded,
+        }
+    }
 
-    pub fn contains_timestamp(&self, timestamp: &DateTime<Utc>) -> bool {
+    /// Do an upper bound and a lower bound meet at a single shared value with
+    /// exactly one of them inclusive? `[a,b)` touches `[b,c)` but `[a,b]` does
+    /// not touch `[b,c]`.
+    fn touch(upper: &Bound<T>, lower: &Bound<T>) -> bool {
+        match (upper, lower) {
+            (Bound::Included(u) | Bound::Excluded(u), Bound::Included(l) | Bound::Excluded(l)) => {
+                matches!(u.partial_cmp(l), Some(Ordering::Equal))
+                    && (matches!(upper, Bound::Included(_)) ^ matches!(lower, Bound::Included(_)))
+            }
+            _ => false,
+        }
+    }
+
+    /// A range is empty when its lower bound is strictly greater than its
+    /// upper bound, or they sit at the same value with at least one exclusive
+    /// endpoint.
+    pub fn is_empty(&self) -> bool {
         match (&self.0.start, &self.0.end) {
-            (Bound::Included(start), Bound::Included(end)) => timestamp >= start && timestamp <= end,
-            (Bound::Included(start), Bound::Excluded(end)) => timestamp >= start && timestamp < end,
-            (Bound::Excluded(start), Bound::Included(end)) => timestamp > start && timestamp <= end,
-            (Bound::Excluded(start), Bound::Excluded(end)) => timestamp > start && timestamp < end,
-            (Bound::Included(start), Bound::Unbounded) => timestamp >= start,
-            (Bound::Excluded(start), Bound::Unbounded) => timestamp > start,
-            (Bound::Unbounded, Bound::Included(end)) => timestamp <= end,
-            (Bound::Unbounded, Bound::Excluded(end)) => timestamp < end,
-            (Bound::Unbounded, Bound::Unbounded) => true,
+            (Bound::Unbounded, _) | (_, Bound::Unbounded) => false,
+            (Bound::Included(l) | Bound::Excluded(l), Bound::Included(h) | Bound::Excluded(h)) => {
+                match l.partial_cmp(h) {
+                    Some(Ordering::Greater) => true,
+                    Some(Ordering::Equal) => {
+                        !(matches!(&self.0.start, Bound::Included(_))
+                            && matches!(&self.0.end, Bound::Included(_)))
+                    }
+                    _ => false,
+                }
+            }
+        }
+    }
+
+    /// The overlap of two ranges: the greater lower bound and the lesser upper
+    /// bound (exclusive wins ties). `None` if the ranges do not overlap.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let start = if Self::cmp_lower(&self.0.start, &other.0.start) == Ordering::Greater {
+            self.0.start.clone()
+        } else {
+            other.0.start.clone()
+        };
+        let end = if Self::cmp_upper(&self.0.end, &other.0.end) == Ordering::Less {
+            self.0.end.clone()
+        } else {
+            other.0.end.clone()
+        };
+        let result = Self::new(start, end);
+        if result.is_empty() {
+            None
+        } else {
+            Some(result)
         }
     }
 
-    pub fn start(&self) -> Option<DateTime<Utc>> {
+    /// The union of two ranges — the lesser lower bound and the greater upper
+    /// bound. Only defined when the ranges overlap or are adjacent; otherwise
+    /// returns [`RangeError::Discontiguous`].
+    pub fn union(&self, other: &Self) -> Result<Self, RangeError> {
+        if self.intersection(other).is_none() && !self.is_adjacent(other) {
+            return Err(RangeError::Discontiguous);
+        }
+        let start = if Self::cmp_lower(&self.0.start, &other.0.start) == Ordering::Less {
+            self.0.start.clone()
+        } else {
+            other.0.start.clone()
+        };
+        let end = if Self::cmp_upper(&self.0.end, &other.0.end) == Ordering::Greater {
+            self.0.end.clone()
+        } else {
+            other.0.end.clone()
+        };
+        Ok(Self::new(start, end))
+    }
+
+    /// Subtract `other` from `self`. Returns `None` when nothing remains, and
+    /// [`RangeError::DifferenceNotContiguous`] when `other` sits strictly
+    /// inside `self` and would split it into two disjoint pieces.
+    pub fn difference(&self, other: &Self) -> Result<Option<Self>, RangeError> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+        if self.intersection(other).is_none() {
+            return Ok(Some(self.clone()));
+        }
+        let left = Self::cmp_lower(&other.0.start, &self.0.start) == Ordering::Greater;
+        let right = Self::cmp_upper(&other.0.end, &self.0.end) == Ordering::Less;
+        match (left, right) {
+            (true, true) => Err(RangeError::DifferenceNotContiguous),
+            (true, false) => Ok(Some(Self::new(
+                self.0.start.clone(),
+                Self::flip(&other.0.start),
+            ))),
+            (false, true) => Ok(Some(Self::new(
+                Self::flip(&other.0.end),
+                self.0.end.clone(),
+            ))),
+            (false, false) => Ok(None),
+        }
+    }
+
+    /// Two ranges are adjacent when the upper bound of one meets the lower
+    /// bound of the other at a shared value with exactly one inclusive
+    /// endpoint.
+    pub fn is_adjacent(&self, other: &Self) -> bool {
+        Self::touch(&self.0.end, &other.0.start) || Self::touch(&other.0.end, &self.0.start)
+    }
+}
+
+impl<T> PgRangeType<T> {
+    /// True only when *both* endpoints are `Bound::Unbounded`.
+    pub fn is_unbounded(&self) -> bool {
+        matches!(self.0.start, Bound::Unbounded) && matches!(self.0.end, Bound::Unbounded)
+    }
+
+    /// The lower bound's contained value, if it has one.
+    pub fn inner_start(&self) -> Option<&T> {
         match &self.0.start {
-            Bound::Included(dt) | Bound::Excluded(dt) => Some(*dt),
+            Bound::Included(v) | Bound::Excluded(v) => Some(v),
             Bound::Unbounded => None,
         }
     }
 
-    pub fn end(&self) -> Option<DateTime<Utc>> {
+    /// The upper bound's contained value, if it has one.
+    pub fn inner_end(&self) -> Option<&T> {
         match &self.0.end {
-            Bound::Included(dt) | Bound::Excluded(dt) => Some(*dt),
+            Bound::Included(v) | Bound::Excluded(v) => Some(v),
             Bound::Unbounded => None,
         }
     }
 
-    // Check if start/end are inclusive
-    pub fn is_start_inclusive(&self) -> bool {
-        matches!(&self.0.start, Bound::Included(_))
+    /// Transform both contained values with `f`, preserving each endpoint's
+    /// inclusivity (and leaving `Unbounded` untouched). Handy for snapping
+    /// endpoints to the nearest hour or shifting a whole range by an offset
+    /// without matching every `(start, end)` combination by hand.
+    pub fn map_bound<U>(&self, f: impl Fn(&T) -> U) -> PgRangeType<U> {
+        PgRangeType::new(map_one(&self.0.start, &f), map_one(&self.0.end, &f))
     }
 
-    pub fn is_end_inclusive(&self) -> bool {
-        matches!(&self.0.end, Bound::Included(_))
+    /// Fallible [`map_bound`](Self::map_bound): short-circuits on the first
+    /// endpoint for which `f` returns an error.
+    pub fn try_map_bound<U, E>(
+        &self,
+        f: impl Fn(&T) -> Result<U, E>,
+    ) -> Result<PgRangeType<U>, E> {
+        Ok(PgRangeType::new(
+            try_map_one(&self.0.start, &f)?,
+            try_map_one(&self.0.end, &f)?,
+        ))
     }
 }
 
+/// Apply `f` to a single bound, keeping its inclusivity.
+fn map_one<T, U>(b: &Bound<T>, f: &impl Fn(&T) -> U) -> Bound<U> {
+    match b {
+        Bound::Included(v) => Bound::Included(f(v)),
+        Bound::Excluded(v) => Bound::Excluded(f(v)),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
 
+/// Fallible counterpart to [`map_one`].
+fn try_map_one<T, U, E>(b: &Bound<T>, f: &impl Fn(&T) -> Result<U, E>) -> Result<Bound<U>, E> {
+    Ok(match b {
+        Bound::Included(v) => Bound::Included(f(v)?),
+        Bound::Excluded(v) => Bound::Excluded(f(v)?),
+        Bound::Unbounded => Bound::Unbounded,
+    })
+}
 
-impl Display for TstzRange {
+impl TstzRange {
+    pub fn from_datetime_pair(start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        Self::new(Bound::Included(start), Bound::Excluded(end))
+    }
+
+    pub fn contains_timestamp(&self, timestamp: &DateTime<Utc>) -> bool {
+        self.contains_value(timestamp)
+    }
+}
+
+/// Renders a [`TstzRange`] with its endpoints printed in a named IANA
+/// timezone. The range is still stored and compared in UTC — this only
+/// affects the textual presentation, the way the `DateTimeTz` newtype prints
+/// zone-local time while leaving the instant untouched.
+#[cfg(feature = "chrono-tz")]
+pub struct DisplayInTz<'a> {
+    range: &'a TstzRange,
+    tz: chrono_tz::Tz,
+}
+
+#[cfg(feature = "chrono-tz")]
+impl Display for DisplayInTz<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let start = match &self.range.0.start {
+            Bound::Included(v) => format!("[{}", v.with_timezone(&self.tz).to_rfc3339()),
+            Bound::Excluded(v) => format!("({}", v.with_timezone(&self.tz).to_rfc3339()),
+            Bound::Unbounded => "(".to_string(),
+        };
+        let end = match &self.range.0.end {
+            Bound::Included(v) => format!("{}]", v.with_timezone(&self.tz).to_rfc3339()),
+            Bound::Excluded(v) => format!("{})", v.with_timezone(&self.tz).to_rfc3339()),
+            Bound::Unbounded => ")".to_string(),
+        };
+        write!(f, "{},{}", start, end)
+    }
+}
+
+#[cfg(feature = "chrono-tz")]
+impl TstzRange {
+    /// Render this range with both endpoints expressed in `tz` (e.g.
+    /// `America/New_York`), for display or logging. Storage and comparison
+    /// remain in UTC.
+    pub fn to_string_in_tz(&self, tz: chrono_tz::Tz) -> String {
+        self.display_in_tz(tz).to_string()
+    }
+
+    /// A [`Display`] wrapper that prints this range in `tz` without allocating
+    /// until formatted.
+    pub fn display_in_tz(&self, tz: chrono_tz::Tz) -> DisplayInTz<'_> {
+        DisplayInTz { range: self, tz }
+    }
+}
+
+impl<T: RangeBound> Display for PgRangeType<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let start = match &self.0.start {
-            Bound::Included(v) => format!("[{}", v.to_rfc3339()),
-            Bound::Excluded(v) => format!("({}", v.to_rfc3339()),
+            Bound::Included(v) => format!("[{}", v.format_bound()),
+            Bound::Excluded(v) => format!("({}", v.format_bound()),
             Bound::Unbounded => "(".to_string(),
         };
         let end = match &self.0.end {
-            Bound::Included(v) => format!("{}]", v.to_rfc3339()),
-            Bound::Excluded(v) => format!("{})", v.to_rfc3339()),
+            Bound::Included(v) => format!("{}]", v.format_bound()),
+            Bound::Excluded(v) => format!("{})", v.format_bound()),
             Bound::Unbounded => ")".to_string(),
         };
         write!(f, "{},{}", start, end)
     }
 }
 
-
-impl Serialize for TstzRange {
+impl<T: Serialize> Serialize for PgRangeType<T> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        S: serde::Serializer
+        S: serde::Serializer,
     {
-
         (&self.0.start, &self.0.end).serialize(serializer)
     }
 }
 
-impl<'de> Deserialize<'de> for TstzRange {
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for PgRangeType<T> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
-        D: serde::Deserializer<'de> {
+        D: serde::Deserializer<'de>,
+    {
         let (start, end) = Deserialize::deserialize(deserializer)?;
-        Ok(TstzRange(PgRange{ start, end }))
+        Ok(PgRangeType(PgRange { start, end }))
     }
 }
 
-
-impl Nullable for TstzRange {
+impl<T: RangeBound> Nullable for PgRangeType<T> {
     fn null() -> Value {
         Value::String(None)
     }
 }
 
-
-impl ValueType for TstzRange {
+impl<T: RangeBound> ValueType for PgRangeType<T> {
     fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
         match v {
             Value::String(Some(s)) => Self::from_string(&s),
@@ -190,7 +612,7 @@ impl ValueType for TstzRange {
     }
 
     fn type_name() -> String {
-        "tstzrange".to_owned()
+        T::TYPE_NAME.to_owned()
     }
 
     fn array_type() -> sea_orm::sea_query::ArrayType {
@@ -198,17 +620,16 @@ impl ValueType for TstzRange {
     }
 
     fn column_type() -> sea_orm::sea_query::ColumnType {
-        sea_orm::sea_query::ColumnType::custom("TSTZRANGE".to_owned())
+        sea_orm::sea_query::ColumnType::custom(T::COLUMN_TYPE.to_owned())
     }
 }
 
-
-impl TryGetable for TstzRange {
+impl<T: RangeBound> TryGetable for PgRangeType<T> {
     fn try_get_by<I: ColIdx>(res: &QueryResult, idx: I) -> Result<Self, TryGetError> {
         let value = res.try_get_by::<Option<String>, I>(idx)?.into();
         match value {
             Value::String(Some(s)) => {
-                let range = TstzRange::from_string(&s)
+                let range = Self::from_string(&s)
                     .map_err(|e| TryGetError::Null(e.to_string()))?;
                 Ok(range)
             }
@@ -217,22 +638,72 @@ impl TryGetable for TstzRange {
     }
 }
 
-impl From<PgRange<DateTime<Utc>>> for TstzRange {
-    fn from(range: PgRange<DateTime<Utc>>) -> Self {
-        TstzRange(range)
+impl<T> From<PgRange<T>> for PgRangeType<T> {
+    fn from(range: PgRange<T>) -> Self {
+        PgRangeType(range)
     }
 }
 
-impl From<TstzRange> for PgRange<DateTime<Utc>> {
-    fn from(range: TstzRange) -> Self {
+impl<T> From<PgRangeType<T>> for PgRange<T> {
+    fn from(range: PgRangeType<T>) -> Self {
         range.0
     }
 }
 
-impl From<TstzRange> for Value {
-    fn from(x: TstzRange) -> Value {
-        let v = Value::String(Some(Box::new(x.to_string())));
-        v
+impl<T: RangeBound> From<PgRangeType<T>> for Value {
+    fn from(x: PgRangeType<T>) -> Value {
+        Value::String(Some(Box::new(x.to_string())))
+    }
+}
+
+/// Native Postgres binary binding for the sqlx executor path.
+///
+/// The [`From<PgRangeType<T>>`](Value) / [`from_string`](PgRangeType::from_string)
+/// round-trip renders a text literal and leans on Postgres to re-parse it,
+/// which is fine for query-builder contexts that only accept a `SimpleExpr`
+/// but loses the binary protocol's fidelity (a Postgres `infinity` bound
+/// value does not parse as text) and speed. Following the route sea-query-binder takes for
+/// first-class array/range binding, this feature-gated path hands the typed
+/// [`PgRange<T>`] straight to sqlx's `sqlx-postgres` encoder/decoder instead.
+#[cfg(feature = "sqlx-postgres")]
+mod sqlx_postgres_impl {
+    use super::PgRangeType;
+    use sqlx::decode::Decode;
+    use sqlx::encode::{Encode, IsNull};
+    use sqlx::error::BoxDynError;
+    use sqlx::postgres::types::PgRange;
+    use sqlx::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueRef, Postgres};
+    use sqlx::Type;
+
+    impl<T> Type<Postgres> for PgRangeType<T>
+    where
+        PgRange<T>: Type<Postgres>,
+    {
+        fn type_info() -> PgTypeInfo {
+            <PgRange<T> as Type<Postgres>>::type_info()
+        }
+
+        fn compatible(ty: &PgTypeInfo) -> bool {
+            <PgRange<T> as Type<Postgres>>::compatible(ty)
+        }
+    }
+
+    impl<'q, T> Encode<'q, Postgres> for PgRangeType<T>
+    where
+        PgRange<T>: Encode<'q, Postgres>,
+    {
+        fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+            <PgRange<T> as Encode<'q, Postgres>>::encode_by_ref(&self.0, buf)
+        }
+    }
+
+    impl<'r, T> Decode<'r, Postgres> for PgRangeType<T>
+    where
+        PgRange<T>: Decode<'r, Postgres>,
+    {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+            Ok(PgRangeType(<PgRange<T> as Decode<'r, Postgres>>::decode(value)?))
+        }
     }
 }
 
@@ -245,6 +716,42 @@ pub trait RangeOps {
 
     // && operator - overlaps with
     fn overlaps<T>(&self, range: T) -> SimpleExpr where T: Into<SimpleExpr>;
+
+    // -|- operator - is adjacent to
+    fn adjacent<T>(&self, range: T) -> SimpleExpr where T: Into<SimpleExpr>;
+
+    // + operator - union
+    fn union<T>(&self, range: T) -> SimpleExpr where T: Into<SimpleExpr>;
+
+    // * operator - intersection
+    fn intersection<T>(&self, range: T) -> SimpleExpr where T: Into<SimpleExpr>;
+
+    // - operator - difference
+    fn difference<T>(&self, range: T) -> SimpleExpr where T: Into<SimpleExpr>;
+
+    // << operator - strictly left of
+    fn strictly_left<T>(&self, range: T) -> SimpleExpr where T: Into<SimpleExpr>;
+
+    // >> operator - strictly right of
+    fn strictly_right<T>(&self, range: T) -> SimpleExpr where T: Into<SimpleExpr>;
+
+    // &< operator - does not extend to the right of
+    fn not_extends_right<T>(&self, range: T) -> SimpleExpr where T: Into<SimpleExpr>;
+
+    // &> operator - does not extend to the left of
+    fn not_extends_left<T>(&self, range: T) -> SimpleExpr where T: Into<SimpleExpr>;
+
+    // < operator - range less than
+    fn range_lt<T>(&self, range: T) -> SimpleExpr where T: Into<SimpleExpr>;
+
+    // > operator - range greater than
+    fn range_gt<T>(&self, range: T) -> SimpleExpr where T: Into<SimpleExpr>;
+
+    // = operator - range equal
+    fn range_eq<T>(&self, range: T) -> SimpleExpr where T: Into<SimpleExpr>;
+
+    // @> timestamptz - contains the given point in time
+    fn contains_point(&self, ts: DateTime<Utc>) -> SimpleExpr;
 }
 
 impl RangeOps for Expr {
@@ -271,6 +778,102 @@ impl RangeOps for Expr {
             Box::new(range.into()),
         )
     }
+
+    fn adjacent<T>(&self, range: T) -> SimpleExpr where T: Into<SimpleExpr> {
+        SimpleExpr::Binary(
+            Box::new(self.clone().into()),
+            sea_orm::sea_query::BinOper::Custom("-|-"),
+            Box::new(range.into()),
+        )
+    }
+
+    fn union<T>(&self, range: T) -> SimpleExpr where T: Into<SimpleExpr> {
+        SimpleExpr::Binary(
+            Box::new(self.clone().into()),
+            sea_orm::sea_query::BinOper::Custom("+"),
+            Box::new(range.into()),
+        )
+    }
+
+    fn intersection<T>(&self, range: T) -> SimpleExpr where T: Into<SimpleExpr> {
+        SimpleExpr::Binary(
+            Box::new(self.clone().into()),
+            sea_orm::sea_query::BinOper::Custom("*"),
+            Box::new(range.into()),
+        )
+    }
+
+    fn difference<T>(&self, range: T) -> SimpleExpr where T: Into<SimpleExpr> {
+        SimpleExpr::Binary(
+            Box::new(self.clone().into()),
+            sea_orm::sea_query::BinOper::Custom("-"),
+            Box::new(range.into()),
+        )
+    }
+
+    fn strictly_left<T>(&self, range: T) -> SimpleExpr where T: Into<SimpleExpr> {
+        SimpleExpr::Binary(
+            Box::new(self.clone().into()),
+            sea_orm::sea_query::BinOper::Custom("<<"),
+            Box::new(range.into()),
+        )
+    }
+
+    fn strictly_right<T>(&self, range: T) -> SimpleExpr where T: Into<SimpleExpr> {
+        SimpleExpr::Binary(
+            Box::new(self.clone().into()),
+            sea_orm::sea_query::BinOper::Custom(">>"),
+            Box::new(range.into()),
+        )
+    }
+
+    fn not_extends_right<T>(&self, range: T) -> SimpleExpr where T: Into<SimpleExpr> {
+        SimpleExpr::Binary(
+            Box::new(self.clone().into()),
+            sea_orm::sea_query::BinOper::Custom("&<"),
+            Box::new(range.into()),
+        )
+    }
+
+    fn not_extends_left<T>(&self, range: T) -> SimpleExpr where T: Into<SimpleExpr> {
+        SimpleExpr::Binary(
+            Box::new(self.clone().into()),
+            sea_orm::sea_query::BinOper::Custom("&>"),
+            Box::new(range.into()),
+        )
+    }
+
+    fn range_lt<T>(&self, range: T) -> SimpleExpr where T: Into<SimpleExpr> {
+        SimpleExpr::Binary(
+            Box::new(self.clone().into()),
+            sea_orm::sea_query::BinOper::Custom("<"),
+            Box::new(range.into()),
+        )
+    }
+
+    fn range_gt<T>(&self, range: T) -> SimpleExpr where T: Into<SimpleExpr> {
+        SimpleExpr::Binary(
+            Box::new(self.clone().into()),
+            sea_orm::sea_query::BinOper::Custom(">"),
+            Box::new(range.into()),
+        )
+    }
+
+    fn range_eq<T>(&self, range: T) -> SimpleExpr where T: Into<SimpleExpr> {
+        SimpleExpr::Binary(
+            Box::new(self.clone().into()),
+            sea_orm::sea_query::BinOper::Custom("="),
+            Box::new(range.into()),
+        )
+    }
+
+    fn contains_point(&self, ts: DateTime<Utc>) -> SimpleExpr {
+        SimpleExpr::Binary(
+            Box::new(self.clone().into()),
+            sea_orm::sea_query::BinOper::Custom("@>"),
+            Box::new(Expr::val(ts).cast_as(sea_orm::sea_query::Alias::new("timestamptz"))),
+        )
+    }
 }
 
 
@@ -362,5 +965,176 @@ mod tests {
         assert!(!range.contains_timestamp(&end));
     }
 
-}
+    #[test]
+    fn test_int4range_round_trip() {
+        let range = Int4Range::new(Bound::Included(1), Bound::Excluded(10));
 
+        let parsed = Int4Range::from_string(&range.to_string()).unwrap();
+        assert_eq!(range, parsed);
+        assert_eq!(<Int4Range as ValueType>::type_name(), "int4range");
+        assert!(range.contains_value(&5));
+        assert!(!range.contains_value(&10));
+    }
+
+    #[test]
+    fn test_bound_mapping() {
+        let start = Utc::now();
+        let end = start + chrono::Duration::days(1);
+        let range = TstzRange::new(Bound::Included(start), Bound::Excluded(end));
+
+        assert!(!range.is_unbounded());
+        assert_eq!(range.inner_start(), Some(&start));
+        assert_eq!(range.inner_end(), Some(&end));
+
+        // shift the whole range forward by an hour, keeping inclusivity
+        let shifted = range.map_bound(|dt| *dt + chrono::Duration::hours(1));
+        assert_eq!(
+            shifted,
+            TstzRange::new(
+                Bound::Included(start + chrono::Duration::hours(1)),
+                Bound::Excluded(end + chrono::Duration::hours(1)),
+            )
+        );
+
+        let unbounded = TstzRange::new(Bound::Unbounded, Bound::Unbounded);
+        assert!(unbounded.is_unbounded());
+
+        let ok: Result<TstzRange, ()> = range.try_map_bound(|dt| Ok(*dt));
+        assert_eq!(ok.unwrap(), range);
+    }
+
+    #[test]
+    fn test_range_algebra() {
+        let t0 = Utc::now();
+        let t1 = t0 + chrono::Duration::hours(1);
+        let t2 = t0 + chrono::Duration::hours(2);
+        let t3 = t0 + chrono::Duration::hours(3);
+
+        let a = TstzRange::new(Bound::Included(t0), Bound::Excluded(t2));
+        let b = TstzRange::new(Bound::Included(t1), Bound::Excluded(t3));
+
+        // intersection
+        let inter = a.intersection(&b).unwrap();
+        assert_eq!(inter, TstzRange::new(Bound::Included(t1), Bound::Excluded(t2)));
+
+        // union of overlapping ranges
+        let uni = a.union(&b).unwrap();
+        assert_eq!(uni, TstzRange::new(Bound::Included(t0), Bound::Excluded(t3)));
+
+        // difference leaves the leading slice
+        let diff = a.difference(&b).unwrap().unwrap();
+        assert_eq!(diff, TstzRange::new(Bound::Included(t0), Bound::Excluded(t1)));
+
+        // adjacency: [t0,t1) touches [t1,t2) but not [t0,t1] vs [t1,t2]
+        let left = TstzRange::new(Bound::Included(t0), Bound::Excluded(t1));
+        let right = TstzRange::new(Bound::Included(t1), Bound::Excluded(t2));
+        assert!(left.is_adjacent(&right));
+        assert!(left.intersection(&right).is_none());
+        assert!(matches!(
+            left.union(&right),
+            Ok(r) if r == TstzRange::new(Bound::Included(t0), Bound::Excluded(t2))
+        ));
+
+        let closed_left = TstzRange::new(Bound::Included(t0), Bound::Included(t1));
+        let closed_right = TstzRange::new(Bound::Included(t1), Bound::Included(t2));
+        assert!(!closed_left.is_adjacent(&closed_right));
+
+        // disjoint, non-adjacent union errors
+        let far = TstzRange::new(Bound::Included(t3), Bound::Unbounded);
+        assert_eq!(left.union(&far), Err(RangeError::Discontiguous));
+    }
+
+    #[test]
+    fn test_empty_and_split_difference() {
+        let t0 = Utc::now();
+        let t1 = t0 + chrono::Duration::hours(1);
+        let t2 = t0 + chrono::Duration::hours(2);
+        let t3 = t0 + chrono::Duration::hours(3);
+
+        assert!(TstzRange::new(Bound::Included(t1), Bound::Excluded(t1)).is_empty());
+        assert!(!TstzRange::new(Bound::Included(t1), Bound::Included(t1)).is_empty());
+
+        let outer = TstzRange::new(Bound::Included(t0), Bound::Excluded(t3));
+        let inner = TstzRange::new(Bound::Included(t1), Bound::Excluded(t2));
+        assert_eq!(outer.difference(&inner), Err(RangeError::DifferenceNotContiguous));
+    }
+
+    #[test]
+    fn test_daterange_round_trip() {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+        let range = DateRange::new(Bound::Included(start), Bound::Excluded(end));
+
+        let parsed = DateRange::from_string(&range.to_string()).unwrap();
+        assert_eq!(range, parsed);
+        assert_eq!(<DateRange as ValueType>::type_name(), "daterange");
+    }
+
+    #[cfg(feature = "with-time")]
+    #[test]
+    fn test_time_backend_round_trip() {
+        use time::OffsetDateTime;
+
+        let start = OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap();
+        let end = OffsetDateTime::from_unix_timestamp(1_700_086_400).unwrap();
+        let range = TstzRangeTime::new(Bound::Included(start), Bound::Excluded(end));
+
+        let parsed = TstzRangeTime::from_string(&range.to_string()).unwrap();
+        assert_eq!(range, parsed);
+        assert_eq!(<TstzRangeTime as ValueType>::type_name(), "tstzrange");
+
+        // The `+00` → `+00:00` fixup shared with the chrono backend must still
+        // let a Postgres-style literal parse.
+        let pg = TstzRangeTime::from_string("[2023-11-14T22:13:20+00,2023-11-15T22:13:20+00)").unwrap();
+        assert_eq!(pg.inner_start(), Some(&start));
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn test_to_string_in_tz() {
+        // A January instant renders in Eastern Standard Time (UTC−05:00),
+        // proving the endpoints are shown zone-local rather than in UTC.
+        let start = "2024-01-01T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let end = "2024-01-02T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let range = TstzRange::new(Bound::Included(start), Bound::Excluded(end));
+
+        let rendered = range.to_string_in_tz(chrono_tz::America::New_York);
+        assert!(rendered.contains("-05:00"), "expected EST offset, got {rendered}");
+        assert!(rendered.contains("07:00:00"), "expected zone-local hour, got {rendered}");
+    }
+
+    #[cfg(feature = "sqlx-postgres")]
+    #[test]
+    fn test_sqlx_native_encode() {
+        use sqlx::encode::{Encode, IsNull};
+        use sqlx::postgres::{types::PgRange, PgArgumentBuffer, Postgres};
+        use sqlx::Type;
+
+        // Our wrapper reports the same wire type as the inner PgRange, so the
+        // native binary path is used rather than the text round-trip.
+        assert_eq!(
+            <TstzRange as Type<Postgres>>::type_info(),
+            <PgRange<DateTime<Utc>> as Type<Postgres>>::type_info(),
+        );
+
+        // A bounded range with sub-second precision encodes natively.
+        let start = "2024-01-01T00:00:00.123456Z".parse::<DateTime<Utc>>().unwrap();
+        let end = "2024-01-02T00:00:00.654321Z".parse::<DateTime<Utc>>().unwrap();
+        let bounded = TstzRange::new(Bound::Included(start), Bound::Excluded(end));
+        let mut buf = PgArgumentBuffer::default();
+        let is_null =
+            <TstzRange as Encode<Postgres>>::encode_by_ref(&bounded, &mut buf).unwrap();
+        assert!(matches!(is_null, IsNull::No));
+        assert!(!buf.is_empty());
+
+        // Unbounded endpoints encode as the range's infinite-bound flags
+        // instead of erroring. (A full decode round-trip requires a live
+        // Postgres connection.)
+        let infinite = TstzRange::new(Bound::Unbounded, Bound::Unbounded);
+        let mut buf = PgArgumentBuffer::default();
+        let is_null =
+            <TstzRange as Encode<Postgres>>::encode_by_ref(&infinite, &mut buf).unwrap();
+        assert!(matches!(is_null, IsNull::No));
+        assert!(!buf.is_empty());
+    }
+}